@@ -1,12 +1,15 @@
 use super::{r#trait::*, r#type::*};
 use once_cell::sync::Lazy;
-use runtime::Runtime;
+use rand::Rng;
+use runtime::{Handle, Runtime};
+use std::future::Future;
 use std::sync::Arc;
 use std::thread::{JoinHandle, spawn};
+use std::time::Duration;
 use task::JoinError;
 use tokio::*;
 
-static GLOBAL_RUNTIME: Lazy<Runtime> = Lazy::new(|| {
+pub(crate) static GLOBAL_RUNTIME: Lazy<Runtime> = Lazy::new(|| {
     loop {
         match Runtime::new() {
             Ok(runtime) => return runtime,
@@ -27,7 +30,7 @@ pub fn run_function<F: AsyncRecoverableFunction>(func: F) -> AsyncSpawnResult {
         };
         return tokio::spawn(func).await;
     });
-    return res;
+    return res.map_err(AsyncSpawnError::from);
 }
 
 /// Executes an error-handling function with a given error message within a panic-safe context.
@@ -46,7 +49,7 @@ pub fn run_error_handle_function<E: AsyncErrorHandlerFunction>(
         };
         return tokio::spawn(func).await;
     });
-    return res;
+    return res.map_err(AsyncSpawnError::from);
 }
 
 /// Executes a recoverable function within a panic-safe context.
@@ -58,7 +61,7 @@ pub async fn async_run_function<F: AsyncRecoverableFunction>(func: F) -> AsyncSp
     let func = async move {
         func.call().await;
     };
-    return tokio::spawn(func).await;
+    return tokio::spawn(func).await.map_err(AsyncSpawnError::from);
 }
 
 /// Executes an error-handling function with a given error message within a panic-safe context.
@@ -74,15 +77,15 @@ pub async fn async_run_error_handle_function<E: AsyncErrorHandlerFunction>(
     let func = async move {
         func.call(error.clone()).await;
     };
-    return tokio::spawn(func).await;
+    return tokio::spawn(func).await.map_err(AsyncSpawnError::from);
 }
 
 /// Converts a panic-captured error value into a string.
 ///
-/// - `err`: The captured error value, of type `JoinError `.
+/// - `err`: The captured error value, of type `AsyncSpawnError`.
 /// - Returns: A string representation of the error value.
 #[inline]
-pub fn tokio_error_to_string(err: JoinError) -> String {
+pub fn tokio_error_to_string(err: AsyncSpawnError) -> String {
     err.to_string()
 }
 
@@ -303,3 +306,581 @@ pub async fn async_recoverable_spawn_catch_finally<F, E, L>(
     }
     let _: AsyncSpawnResult = async_run_function(finally).await;
 }
+
+/// Configuration for retrying a recoverable task after it panics, with exponential backoff. This
+/// is the crate's sole retry policy type, shared by `recoverable_spawn_retry`,
+/// `async_recoverable_spawn_retry`, and `async_recoverable_spawn_retry_until`.
+///
+/// - `max_attempts`: The maximum number of attempts to make, inclusive of the first. `None` retries forever.
+/// - `initial_delay`: The delay before the first retry.
+/// - `multiplier`: The factor the delay is multiplied by after each subsequent failed attempt.
+/// - `max_delay`: The upper bound the computed delay is clamped to.
+/// - `jitter`: When `true`, applies "full jitter" (the delay is drawn uniformly from
+///   `0..=computed_delay` on each retry) so concurrently-restarting tasks don't all wake up and
+///   retry in lockstep. When `false`, the computed delay is used as-is.
+///
+/// Attempts are numbered starting from 1 (the first attempt, before any retry, is attempt 1), and
+/// `delay_for_retry` computes the backoff before the `retry_number`-th retry accordingly.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_attempts: Option<usize>,
+    pub initial_delay: Duration,
+    pub multiplier: u32,
+    pub max_delay: Duration,
+    pub jitter: bool,
+}
+
+impl RetryPolicy {
+    /// Computes the backoff delay before the `retry_number`-th retry (1-based), applying full
+    /// jitter if `self.jitter` is set.
+    fn delay_for_retry(&self, retry_number: usize) -> Duration {
+        let exponent: u32 = (retry_number - 1) as u32;
+        let capped: Duration = self
+            .initial_delay
+            .saturating_mul(self.multiplier.saturating_pow(exponent))
+            .min(self.max_delay);
+        if !self.jitter {
+            return capped;
+        }
+        let capped_nanos: u64 = capped.as_nanos().min(u64::MAX as u128) as u64;
+        Duration::from_nanos(rand::rng().random_range(0..=capped_nanos))
+    }
+}
+
+/// The outcome of a retry loop: how many attempts were made, and -- if the retry budget was
+/// exhausted without the function ever succeeding -- the panic payload captured from the last
+/// attempt, so callers don't have to reconstruct it from the stringified message the
+/// per-attempt `error_handle_function` callback already received.
+#[derive(Debug)]
+pub struct RetryOutcome {
+    pub attempts: usize,
+    pub last_panic: Option<BoxAnySend>,
+}
+
+/// Spawns a new thread that re-invokes the recoverable function produced by `factory` whenever it
+/// panics, following `policy`'s backoff schedule, until it either succeeds or the retry budget is
+/// exhausted.
+///
+/// # Parameters
+/// - `factory`: Produces a fresh instance of the function to run on each attempt, since a panicked
+///   attempt's `AsyncRecoverableFunction` has already been consumed.
+/// - `error_handle_function`: Called after each failed attempt with the error message and the
+///   1-based number of the attempt that just failed.
+/// - `policy`: The `RetryPolicy` governing the maximum number of attempts and the backoff delay.
+///
+/// # Returns
+/// - A `JoinHandle<RetryOutcome>` carrying the number of attempts made and, if every attempt
+///   failed, the last captured panic payload, joinable once the function succeeds or the retry
+///   budget is exhausted.
+#[inline]
+pub fn recoverable_spawn_retry<Fac, F, E>(
+    factory: Fac,
+    mut error_handle_function: E,
+    policy: RetryPolicy,
+) -> JoinHandle<RetryOutcome>
+where
+    Fac: Fn() -> F + Send + Sync + 'static,
+    F: AsyncRecoverableFunction,
+    E: FnMut(&str, usize) + Send + 'static,
+{
+    spawn(move || {
+        let mut attempt: usize = 0;
+        loop {
+            attempt += 1;
+            let run_result: AsyncSpawnResult = run_function(factory());
+            match run_result {
+                Ok(()) => {
+                    return RetryOutcome {
+                        attempts: attempt,
+                        last_panic: None,
+                    }
+                }
+                Err(err) => {
+                    let err_string: String = err.to_string();
+                    error_handle_function(&err_string, attempt);
+                    if policy.max_attempts.is_some_and(|max| attempt >= max) {
+                        let last_panic: Option<BoxAnySend> =
+                            err.is_panic().then(|| err.into_panic());
+                        return RetryOutcome {
+                            attempts: attempt,
+                            last_panic,
+                        };
+                    }
+                    std::thread::sleep(policy.delay_for_retry(attempt));
+                }
+            }
+        }
+    })
+}
+
+/// Asynchronous counterpart of `recoverable_spawn_retry`, re-invoking the recoverable function
+/// produced by `factory` whenever it panics, following `policy`'s backoff schedule.
+///
+/// - Returns: A `RetryOutcome` carrying the number of attempts made and, if every attempt failed,
+///   the last captured panic payload.
+#[inline]
+pub async fn async_recoverable_spawn_retry<Fac, F, E>(
+    factory: Fac,
+    mut error_handle_function: E,
+    policy: RetryPolicy,
+) -> RetryOutcome
+where
+    Fac: Fn() -> F + Send + Sync + 'static,
+    F: AsyncRecoverableFunction,
+    E: FnMut(&str, usize) + Send + 'static,
+{
+    let mut attempt: usize = 0;
+    loop {
+        attempt += 1;
+        let run_result: AsyncSpawnResult = async_run_function(factory()).await;
+        match run_result {
+            Ok(()) => {
+                return RetryOutcome {
+                    attempts: attempt,
+                    last_panic: None,
+                }
+            }
+            Err(err) => {
+                let err_string: String = err.to_string();
+                error_handle_function(&err_string, attempt);
+                if policy.max_attempts.is_some_and(|max| attempt >= max) {
+                    let last_panic: Option<BoxAnySend> = err.is_panic().then(|| err.into_panic());
+                    return RetryOutcome {
+                        attempts: attempt,
+                        last_panic,
+                    };
+                }
+                time::sleep(policy.delay_for_retry(attempt)).await;
+            }
+        }
+    }
+}
+
+/// A channel-backed handle to a recoverable task's computed output.
+///
+/// Unlike `JoinHandle<()>`, this lets the caller retrieve the value the spawned closure produced
+/// instead of only observing whether it ran to completion.
+pub struct RecoverableHandle<T> {
+    receiver: sync::oneshot::Receiver<SpawnResult<T>>,
+}
+
+impl<T> RecoverableHandle<T> {
+    /// Blocks the current thread until the spawned closure produces its result.
+    ///
+    /// - Returns: A `SpawnResult<T>` carrying the closure's return value, or the panic that occurred.
+    ///   If the sending side was dropped without sending (the spawned thread panicked before it
+    ///   could report a result), a generic message is substituted for the missing payload.
+    pub fn join(self) -> SpawnResult<T> {
+        self.receiver.blocking_recv().unwrap_or_else(|_| {
+            Err(Box::new("recoverable task ended without reporting a result") as BoxAnySend)
+        })
+    }
+}
+
+/// Spawns a new thread to run `function`, capturing its return value instead of discarding it.
+///
+/// - `function`: A closure producing a value of type `T`. If it panics, the panic is caught and
+///   reported through the returned handle instead of propagating.
+/// - Returns: A `RecoverableHandle<T>` that can be joined to retrieve the closure's return value,
+///   or the panic that occurred.
+#[inline]
+pub fn recoverable_spawn_result<F, T>(function: F) -> RecoverableHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let (sender, receiver) = sync::oneshot::channel();
+    spawn(move || {
+        let result: SpawnResult<T> =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(function));
+        let _ = sender.send(result);
+    });
+    RecoverableHandle { receiver }
+}
+
+/// Asynchronous counterpart of `recoverable_spawn_result`, returning the tokio `JoinHandle<F::Output>`
+/// directly so the task's value, rather than `()`, can be awaited.
+///
+/// - `function`: A function implementing the `AsyncRecoverableFunction` trait.
+/// - Returns: A `task::JoinHandle<F::Output>` resolving to the function's return value, or a
+///   `JoinError` if it panicked.
+#[inline]
+pub fn async_recoverable_spawn_result<F: AsyncRecoverableFunction>(
+    function: F,
+) -> task::JoinHandle<F::Output> {
+    tokio::spawn(function.call())
+}
+
+/// Selects which asynchronous runtime backend a recoverable task is dispatched on, mirroring the
+/// runtime-selector pattern used by crates like deadpool.
+///
+/// Note on runtime independence: selecting a backend here only governs which `Handle`/`Runtime`
+/// `block_on`s the task -- the task itself is still scheduled with `tokio::spawn`/`task::JoinHandle`
+/// at every call site in this module and `thread::spawn`, which are tokio-specific. The `Handle`
+/// and `Runtime` types used here are `tokio::runtime` types, so every variant selects a tokio
+/// runtime; there is no non-tokio (e.g. async-std) backend.
+pub enum RuntimeKind {
+    /// Use the crate's lazily-initialized global tokio runtime (`GLOBAL_RUNTIME`).
+    Tokio1,
+    /// Reuse an existing tokio runtime the caller already manages, via its `Handle`.
+    Handle(Handle),
+    /// Reuse the ambient tokio runtime the caller is already running on, if any (detected via
+    /// `Handle::try_current()`), falling back to `GLOBAL_RUNTIME` otherwise. Lets a caller that
+    /// may or may not already be inside a runtime use one `RuntimeKind` value either way, instead
+    /// of having to branch and construct `Handle(...)` vs `Tokio1` itself.
+    Current,
+}
+
+impl RuntimeKind {
+    fn block_on<F: std::future::Future>(&self, future: F) -> F::Output {
+        match self {
+            Self::Tokio1 => GLOBAL_RUNTIME.block_on(future),
+            Self::Handle(handle) => handle.block_on(future),
+            Self::Current => Handle::try_current()
+                .unwrap_or_else(|_| GLOBAL_RUNTIME.handle().clone())
+                .block_on(future),
+        }
+    }
+
+    /// Resolves `Current` against the calling thread's ambient runtime, so the choice is made
+    /// before the task moves to a freshly spawned OS thread, where no runtime is ever current.
+    fn resolve_current(self) -> Self {
+        match self {
+            Self::Current => match Handle::try_current() {
+                Ok(handle) => Self::Handle(handle),
+                Err(_) => Self::Tokio1,
+            },
+            other => other,
+        }
+    }
+}
+
+/// Executes a recoverable function within a panic-safe context, dispatching it on the given
+/// `RuntimeKind` instead of always blocking on the crate's global runtime.
+///
+/// - `func`: A function implementing the `AsyncRecoverableFunction` trait.
+/// - `runtime`: The `RuntimeKind` to dispatch the task on.
+/// - Returns: A `AsyncSpawnResult` indicating the success or failure of the function execution.
+#[inline]
+pub fn run_function_on<F: AsyncRecoverableFunction>(
+    func: F,
+    runtime: &RuntimeKind,
+) -> AsyncSpawnResult {
+    let result: Result<(), JoinError> = runtime.block_on(async move {
+        let func = async move {
+            func.call().await;
+        };
+        tokio::spawn(func).await
+    });
+    result.map_err(AsyncSpawnError::from)
+}
+
+/// Spawns a new thread to run `function` in a recoverable manner, dispatching it on the given
+/// `RuntimeKind` rather than always blocking on the crate's global runtime. Named `..._on_runtime`
+/// (rather than `recoverable_spawn_on`) to avoid colliding with
+/// `thread::spawn::recoverable_spawn_on_handle`, which selects a runtime via a bare `Handle`
+/// instead of a `RuntimeKind`.
+///
+/// - Returns: A `JoinHandle<()>` representing the spawned thread. The thread can be joined later
+///   to wait for its completion.
+#[inline]
+pub fn recoverable_spawn_on_runtime<F>(function: F, runtime: RuntimeKind) -> JoinHandle<()>
+where
+    F: AsyncRecoverableFunction,
+{
+    let runtime: RuntimeKind = runtime.resolve_current();
+    spawn(move || {
+        let _: AsyncSpawnResult = run_function_on(function, &runtime);
+    })
+}
+
+/// Spawns a recoverable function, dispatched on the given `RuntimeKind`, racing it against a
+/// timeout. If the deadline elapses first, the in-flight task is aborted and the error handler is
+/// invoked with a timeout message (`AsyncSpawnError::Timeout`) instead of the task's own panic (if any).
+///
+/// - `function`: The primary function to execute, implementing the `AsyncRecoverableFunction` trait.
+/// - `error_handle_function`: A function to handle errors, implementing the `AsyncErrorHandlerFunction` trait.
+/// - `runtime`: The `RuntimeKind` to dispatch the task on.
+/// - `timeout`: The maximum `Duration` to let `function` run before it is aborted.
+/// - Returns: A `JoinHandle<()>` representing the spawned thread.
+#[inline]
+pub fn recoverable_spawn_timeout_on<F, E>(
+    function: F,
+    error_handle_function: E,
+    runtime: RuntimeKind,
+    timeout: Duration,
+) -> JoinHandle<()>
+where
+    F: AsyncRecoverableFunction,
+    E: AsyncErrorHandlerFunction,
+{
+    let runtime: RuntimeKind = runtime.resolve_current();
+    spawn(move || {
+        let run_result: AsyncSpawnResult = runtime.block_on(async {
+            let mut task: task::JoinHandle<()> = tokio::spawn(async move {
+                function.call().await;
+            });
+            select! {
+                res = &mut task => res.map_err(AsyncSpawnError::from),
+                _ = time::sleep(timeout) => {
+                    task.abort();
+                    let _ = (&mut task).await;
+                    Err(AsyncSpawnError::Timeout)
+                }
+            }
+        });
+        if let Err(err) = run_result {
+            let err_string: String = tokio_error_to_string(err);
+            let _: AsyncSpawnResult =
+                run_error_handle_function(error_handle_function, Arc::new(err_string));
+        }
+    })
+}
+
+/// Spawns a new thread to run the provided function `function` in a recoverable manner, invoking
+/// `error_handle_function` with a structured `PanicInfo` (rather than a stringified message) if
+/// it panics. Site info (`file`/`line`/`column`/`backtrace`) is not captured on this path -- use
+/// `async_recoverable_spawn_catch_info` if that's needed -- so those fields come back `None`.
+///
+/// - `function`: The primary function to execute, implementing the `AsyncRecoverableFunction` trait.
+/// - `error_handle_function`: A function to handle errors, implementing the `PanicInfoHandlerFunction` trait.
+/// - Returns: A `JoinHandle<()>` that can be used to manage the spawned thread.
+#[inline]
+pub fn recoverable_spawn_catch_structured<F, E>(
+    function: F,
+    error_handle_function: E,
+) -> JoinHandle<()>
+where
+    F: AsyncRecoverableFunction,
+    E: PanicInfoHandlerFunction,
+{
+    spawn(|| {
+        let run_result: AsyncSpawnResult = run_function(function);
+        if let Err(err) = run_result {
+            let info: PanicInfo = err.into();
+            error_handle_function(&info);
+        }
+    })
+}
+
+/// Asynchronous counterpart of `recoverable_spawn_catch_structured`, invoking
+/// `error_handle_function` with a structured `PanicInfo` if `function` panics.
+///
+/// - `function`: The primary function to execute, implementing the `AsyncRecoverableFunction` trait.
+/// - `error_handle_function`: A function to handle errors, implementing the `AsyncPanicInfoHandlerFunction` trait.
+#[inline]
+pub async fn async_recoverable_spawn_catch_structured<F, E>(function: F, error_handle_function: E)
+where
+    F: AsyncRecoverableFunction,
+    E: AsyncPanicInfoHandlerFunction,
+{
+    let run_result: AsyncSpawnResult = async_run_function(function).await;
+    if let Err(err) = run_result {
+        let info: Arc<PanicInfo> = Arc::new(err.into());
+        error_handle_function.call(info).await;
+    }
+}
+
+/// Spawns a recoverable function whose errors are handled by a reusable, `Arc`-shared
+/// `ErrorHandler` instead of a one-shot closure, so the same handler instance can be reused
+/// across many spawns, retries, and finally-blocks without being re-allocated each time.
+///
+/// - `function`: The primary function to execute, implementing the `AsyncRecoverableFunction` trait.
+/// - `error_handler`: An `Arc`-shared handler implementing the `ErrorHandler` trait.
+#[inline]
+pub async fn async_recoverable_spawn_catch_shared<F>(
+    function: F,
+    error_handler: Arc<dyn ErrorHandler>,
+) where
+    F: AsyncRecoverableFunction,
+{
+    let run_result: AsyncSpawnResult = async_run_function(function).await;
+    if let Err(err) = run_result {
+        let err_string: String = tokio_error_to_string(err);
+        error_handler.handle(Arc::new(err_string)).await;
+    }
+}
+
+/// Asynchronous counterpart of `recoverable_spawn_catch_info`, invoking `error_handle_function`
+/// with the full `PanicInfo` (payload, thread name, source location, backtrace) if `function`
+/// panics, rather than the bare `Arc<String>` that `async_recoverable_spawn_catch` passes.
+/// Existing `AsyncErrorHandlerFunction` handlers are unaffected and keep working unchanged; this
+/// is an additive entry point for callers that want the full context.
+///
+/// - `function`: The primary function to execute, implementing the `AsyncRecoverableFunction` trait.
+/// - `error_handle_function`: A function to handle the panic, implementing the `AsyncPanicInfoHandlerFunction` trait.
+#[inline]
+pub async fn async_recoverable_spawn_catch_info<F, E>(function: F, error_handle_function: E)
+where
+    F: AsyncRecoverableFunction,
+    E: AsyncPanicInfoHandlerFunction,
+{
+    let prior_hook = install_panic_site_hook();
+    let run_result: AsyncSpawnResult = async_run_function(function).await;
+    restore_panic_site_hook(prior_hook);
+    if let Err(err) = run_result {
+        let info: PanicInfo = panic_info_from_async_spawn_error(err);
+        error_handle_function.call(Arc::new(info)).await;
+    }
+}
+
+/// Drives `func` on a fresh `tokio::task::LocalSet`, enabling `!Send` futures (e.g. ones touching
+/// `Rc`/`RefCell`) that `async_run_function`'s `Send` bound rules out.
+///
+/// - `func`: A function implementing the `LocalAsyncRecoverableFunction` trait.
+/// - Returns: `Ok` with the function's return value, or the `AsyncSpawnError` if it panicked.
+#[inline]
+pub async fn async_run_function_local<F: LocalAsyncRecoverableFunction>(
+    func: F,
+) -> AsyncSpawnResult<F::Output> {
+    task::LocalSet::new()
+        .run_until(async move { task::spawn_local(func.call()).await })
+        .await
+        .map_err(AsyncSpawnError::from)
+}
+
+/// `!Send` counterpart of `async_recoverable_spawn`, driving `function` on a `LocalSet` instead
+/// of requiring its future to be `Send`.
+///
+/// - `function`: The primary function to execute, implementing the `LocalAsyncRecoverableFunction` trait.
+#[inline]
+pub async fn async_recoverable_spawn_local<F>(function: F)
+where
+    F: LocalAsyncRecoverableFunction,
+{
+    let _: AsyncSpawnResult<F::Output> = async_run_function_local(function).await;
+}
+
+/// `!Send` counterpart of `async_recoverable_spawn_catch`, driving `function` on a `LocalSet`
+/// instead of requiring its future to be `Send`.
+///
+/// - `function`: The primary function to execute, implementing the `LocalAsyncRecoverableFunction` trait.
+/// - `error_handle_function`: A function to handle errors, implementing the `AsyncErrorHandlerFunction` trait.
+#[inline]
+pub async fn async_recoverable_spawn_local_catch<F, E>(function: F, error_handle_function: E)
+where
+    F: LocalAsyncRecoverableFunction,
+    E: AsyncErrorHandlerFunction,
+{
+    let run_result: AsyncSpawnResult<F::Output> = async_run_function_local(function).await;
+    if let Err(err) = run_result {
+        let err_string: String = tokio_error_to_string(err);
+        let _: AsyncSpawnResult =
+            async_run_error_handle_function(error_handle_function, Arc::new(err_string)).await;
+    }
+}
+
+/// `!Send` counterpart of `async_recoverable_spawn_catch_finally`, driving `function` and
+/// `finally` on a `LocalSet` instead of requiring their futures to be `Send`. `finally` always
+/// runs, whether or not `function` panicked.
+///
+/// - `function`: The primary function to execute, implementing the `LocalAsyncRecoverableFunction` trait.
+/// - `error_handle_function`: A function to handle errors, implementing the `AsyncErrorHandlerFunction` trait.
+/// - `finally`: A function that always runs after `function` (and its error handler, if any), implementing the `LocalAsyncRecoverableFunction` trait.
+#[inline]
+pub async fn async_recoverable_spawn_local_catch_finally<F, E, L>(
+    function: F,
+    error_handle_function: E,
+    finally: L,
+) where
+    F: LocalAsyncRecoverableFunction,
+    E: AsyncErrorHandlerFunction,
+    L: LocalAsyncRecoverableFunction,
+{
+    let run_result: AsyncSpawnResult<F::Output> = async_run_function_local(function).await;
+    if let Err(err) = run_result {
+        let err_string: String = tokio_error_to_string(err);
+        let _: AsyncSpawnResult =
+            async_run_error_handle_function(error_handle_function, Arc::new(err_string)).await;
+    }
+    let _: AsyncSpawnResult<L::Output> = async_run_function_local(finally).await;
+}
+
+/// Drives `function` as a recoverable task, racing it against a cooperative `shutdown` signal
+/// (e.g. a `oneshot::Receiver`, as in tokio's `serve_with_shutdown`) rather than a fixed
+/// `Duration` like `recoverable_spawn_timeout_on`. If `function` finishes first, its result is
+/// handled as normal; if `shutdown` resolves first, the in-flight task is aborted and
+/// `error_handle_function` is invoked with a cancellation message instead of `function`'s own
+/// panic (if it happened to race with shutdown).
+///
+/// - `function`: The primary function to execute, implementing the `AsyncRecoverableFunction` trait.
+/// - `error_handle_function`: A function to handle errors, implementing the `AsyncErrorHandlerFunction` trait.
+/// - `shutdown`: A future that, once it resolves, requests `function` be cancelled.
+#[inline]
+pub async fn async_recoverable_spawn_until<F, E, S>(
+    function: F,
+    error_handle_function: E,
+    shutdown: S,
+) where
+    F: AsyncRecoverableFunction,
+    E: AsyncErrorHandlerFunction,
+    S: Future<Output = ()>,
+{
+    let mut task: task::JoinHandle<F::Output> = tokio::spawn(function.call());
+    pin!(shutdown);
+    let run_result: AsyncSpawnResult<F::Output> = select! {
+        res = &mut task => res.map_err(AsyncSpawnError::from),
+        _ = &mut shutdown => {
+            task.abort();
+            let _ = (&mut task).await;
+            Err(AsyncSpawnError::Cancelled)
+        }
+    };
+    if let Err(err) = run_result {
+        let err_string: String = tokio_error_to_string(err);
+        let _: AsyncSpawnResult =
+            async_run_error_handle_function(error_handle_function, Arc::new(err_string)).await;
+    }
+}
+
+/// `async_recoverable_spawn_retry` variant that also races a cooperative `shutdown` signal
+/// against both the current attempt and the backoff wait between attempts, so a shutdown request
+/// wins over an in-progress restart instead of having to wait for the current attempt (or
+/// backoff) to finish first.
+///
+/// - Returns: A `RetryOutcome` carrying the number of attempts made and, if the retry budget was
+///   exhausted or `shutdown` resolved before a success, the last captured panic payload (`None`
+///   if `shutdown` cut things short before any attempt had panicked since the last success).
+#[inline]
+pub async fn async_recoverable_spawn_retry_until<Fac, F, E, S>(
+    factory: Fac,
+    mut error_handle_function: E,
+    policy: RetryPolicy,
+    shutdown: S,
+) -> RetryOutcome
+where
+    Fac: Fn() -> F + Send + Sync + 'static,
+    F: AsyncRecoverableFunction,
+    E: FnMut(&str, usize) + Send + 'static,
+    S: Future<Output = ()>,
+{
+    pin!(shutdown);
+    let mut attempt: usize = 0;
+    let mut last_panic: Option<BoxAnySend> = None;
+    loop {
+        attempt += 1;
+        let mut task: task::JoinHandle<F::Output> = tokio::spawn(factory().call());
+        let run_result: Result<F::Output, JoinError> = select! {
+            res = &mut task => res,
+            _ = &mut shutdown => {
+                task.abort();
+                return RetryOutcome { attempts: attempt, last_panic };
+            }
+        };
+        match run_result {
+            Ok(_) => return RetryOutcome { attempts: attempt, last_panic: None },
+            Err(err) => {
+                let err_string: String = err.to_string();
+                error_handle_function(&err_string, attempt);
+                last_panic = err.is_panic().then(|| err.into_panic());
+                if policy.max_attempts.is_some_and(|max| attempt >= max) {
+                    return RetryOutcome { attempts: attempt, last_panic };
+                }
+                select! {
+                    _ = time::sleep(policy.delay_for_retry(attempt)) => {}
+                    _ = &mut shutdown => return RetryOutcome { attempts: attempt, last_panic },
+                }
+            }
+        }
+    }
+}
@@ -86,6 +86,160 @@ async fn test_async_async_recoverable_spawn_catch_finally() {
     .await;
 }
 
+#[test]
+fn test_async_recoverable_spawn_retry() {
+    use crate::{JoinHandle, r#async::*};
+    let msg: &str = "test";
+    let handle: JoinHandle<RetryOutcome> = recoverable_spawn_retry(
+        move || async move {
+            panic!("{}", msg);
+        },
+        |err, attempt| {
+            println!("handle error => {} (attempt {})", err, attempt);
+        },
+        RetryPolicy {
+            max_attempts: Some(3),
+            initial_delay: std::time::Duration::from_millis(1),
+            multiplier: 2,
+            max_delay: std::time::Duration::from_millis(10),
+            jitter: false,
+        },
+    );
+    let _ = handle.join();
+}
+
+#[tokio::test]
+async fn test_async_async_recoverable_spawn_retry() {
+    use crate::r#async::*;
+    let msg: &str = "test";
+    let outcome: RetryOutcome = async_recoverable_spawn_retry(
+        move || async move {
+            panic!("{}", msg);
+        },
+        |err, attempt| {
+            println!("async handle error => {} (attempt {})", err, attempt);
+        },
+        RetryPolicy {
+            max_attempts: Some(3),
+            initial_delay: std::time::Duration::from_millis(1),
+            multiplier: 2,
+            max_delay: std::time::Duration::from_millis(10),
+            jitter: true,
+        },
+    )
+    .await;
+    println!(
+        "test_async_async_recoverable_spawn_retry attempts {}",
+        outcome.attempts
+    );
+}
+
+#[test]
+fn test_recoverable_spawn_result() {
+    use crate::r#async::*;
+    let handle: RecoverableHandle<i32> = recoverable_spawn_result(move || 1 + 1);
+    let res = handle.join();
+    println!("test_recoverable_spawn_result res {res:?}");
+}
+
+#[tokio::test]
+async fn test_async_recoverable_spawn_result() {
+    use crate::r#async::*;
+    let handle = async_recoverable_spawn_result(move || async move { 1 + 1 });
+    let res = handle.await;
+    println!("test_async_recoverable_spawn_result res {res:?}");
+}
+
+#[test]
+fn test_recoverable_spawn_catch_structured() {
+    use crate::{JoinHandle, r#async::*};
+    let msg: &str = "test";
+    let handle: JoinHandle<()> = recoverable_spawn_catch_structured(
+        move || async move {
+            panic!("{}", msg);
+        },
+        |err| {
+            println!("structured error => {} (is_panic {})", err.message(), err.is_panic());
+        },
+    );
+    let _ = handle.join();
+}
+
+#[tokio::test]
+async fn test_async_recoverable_spawn_catch_structured() {
+    use crate::r#async::*;
+    let msg: &str = "test";
+    async_recoverable_spawn_catch_structured(
+        move || async move {
+            panic!("{}", msg);
+        },
+        move |err| async move {
+            println!(
+                "async structured error => {} (is_panic {})",
+                err.message(),
+                err.is_panic()
+            );
+        },
+    )
+    .await;
+}
+
+#[test]
+fn test_recoverable_spawn_on_runtime() {
+    use crate::{JoinHandle, r#async::*};
+    let msg: &str = "test";
+    let handle: JoinHandle<()> = recoverable_spawn_on_runtime(
+        move || async move {
+            panic!("{}", msg);
+        },
+        RuntimeKind::Tokio1,
+    );
+    let _ = handle.join();
+}
+
+#[test]
+fn test_recoverable_spawn_timeout_on() {
+    use crate::{JoinHandle, r#async::*};
+    let handle: JoinHandle<()> = recoverable_spawn_timeout_on(
+        move || async move {
+            tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+        },
+        move |err| async move {
+            println!("timeout handle error => {}", err);
+        },
+        RuntimeKind::Tokio1,
+        std::time::Duration::from_millis(10),
+    );
+    let _ = handle.join();
+}
+
+#[tokio::test]
+async fn test_async_recoverable_spawn_catch_shared() {
+    use crate::r#async::*;
+    use std::sync::Arc;
+
+    let handler: Arc<dyn ErrorHandler> = Arc::new(|err: Arc<String>| async move {
+        println!("shared handle error => {}", err);
+    });
+
+    let msg: &str = "test";
+    async_recoverable_spawn_catch_shared(
+        move || async move {
+            panic!("{}", msg);
+        },
+        handler.clone(),
+    )
+    .await;
+
+    async_recoverable_spawn_catch_shared(
+        move || async move {
+            panic!("reused");
+        },
+        handler,
+    )
+    .await;
+}
+
 #[test]
 fn test_recoverable_spawn() {
     use crate::{JoinHandle, r#sync::*};
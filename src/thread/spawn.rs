@@ -1,54 +1,64 @@
+use super::r#async::GLOBAL_RUNTIME;
 use super::{r#trait::*, r#type::*};
-use runtime::Runtime;
+use runtime::Handle;
 use std::sync::Arc;
 use std::thread::{spawn, JoinHandle};
+use std::time::Duration;
 use task::JoinError;
 use tokio::*;
 
+/// Returns a `Handle` to the caller's ambient tokio runtime if one is active, falling back to
+/// the crate's lazily-initialized global runtime (the same `GLOBAL_RUNTIME` that
+/// `thread::r#async`'s `RuntimeKind::Tokio1` uses) when called from outside any runtime.
+#[inline]
+fn ambient_or_global_handle() -> Handle {
+    Handle::try_current().unwrap_or_else(|_| GLOBAL_RUNTIME.handle().clone())
+}
+
 /// Executes a recoverable function within a panic-safe context.
 ///
 /// - `func`: A function implementing the `AsyncRecoverableFunction` trait.
-/// - Returns: A `AsyncSpawnResult` indicating the success or failure of the function execution.
+/// - `handle`: A `Handle` to the ambient tokio runtime the caller is already part of.
+/// - Returns: A `AsyncSpawnResult` carrying the function's return value, or the panic that occurred.
 #[inline]
-pub fn async_run_function<F: AsyncRecoverableFunction>(func: F) -> AsyncSpawnResult {
-    if let Ok(rt) = Runtime::new() {
-        let _ = rt.block_on(async move {
-            let func = async move {
-                func.call().await;
-            };
-            return tokio::spawn(func).await;
-        });
-    }
-    return Ok(());
+pub fn async_run_function<F: AsyncRecoverableFunction>(
+    func: F,
+    handle: Handle,
+) -> AsyncSpawnResult<F::Output> {
+    let result: Result<F::Output, JoinError> = handle.block_on(async move {
+        let func = async move { func.call().await };
+        tokio::spawn(func).await
+    });
+    result.map_err(AsyncSpawnError::from)
 }
 
 /// Executes an error-handling function with a given error message within a panic-safe context.
 ///
 /// - `func`: A function implementing the `AsyncErrorHandlerFunction` trait.
 /// - `error`: A string slice representing the error message.
+/// - `handle`: A `Handle` to the ambient tokio runtime the caller is already part of.
 /// - Returns: A `AsyncSpawnResult` indicating the success or failure of the error-handling function execution.
 #[inline]
 pub fn async_run_error_handle_function<E: AsyncErrorHandlerFunction>(
     func: E,
     error: String,
-) -> AsyncSpawnResult {
-    if let Ok(rt) = Runtime::new() {
-        let _ = rt.block_on(async move {
-            let func = async move {
-                func.call(Arc::new(error)).await;
-            };
-            return tokio::spawn(func).await;
-        });
-    }
-    return Ok(());
+    handle: Handle,
+) -> AsyncSpawnResult<()> {
+    let result: Result<(), JoinError> = handle.block_on(async move {
+        let func = async move {
+            func.call(Arc::new(error)).await;
+        };
+        tokio::spawn(func).await
+    });
+    result.map_err(AsyncSpawnError::from)
 }
 
 /// Converts a panic-captured error value into a string.
 ///
-/// - `err`: The captured error value, of type `JoinError `.
+/// - `err`: The captured error value, of type `AsyncSpawnError`.
 /// - Returns: A string representation of the error value.
 #[inline]
-pub fn tokio_error_to_string(err: JoinError) -> String {
+pub fn tokio_error_to_string(err: &AsyncSpawnError) -> String {
     err.to_string()
 }
 
@@ -64,19 +74,164 @@ pub fn tokio_error_to_string(err: JoinError) -> String {
 ///     - `'static`: The function does not contain references to non-static data (i.e., data that lives beyond the function's scope).
 ///
 /// # Returns
-/// - A `JoinHandle<()>` representing the spawned thread. The thread can be joined later to wait for its completion.
+/// - A `JoinHandle<AsyncSpawnResult<F::Output>>` representing the spawned thread. The thread can be
+///   joined later to retrieve the function's return value, or the panic that occurred.
 ///
 ///
 /// # Panics
 /// - This function itself will not panic, but the function `function` could panic during execution.
 ///   The panic will be caught, preventing the program from crashing.
+/// - Never panics due to the runtime lookup itself: the spawned thread reuses the caller's
+///   ambient runtime if one is active, falling back to the crate's global runtime otherwise.
 #[inline]
-pub fn async_recoverable_spawn<F>(function: F) -> JoinHandle<()>
+pub fn async_recoverable_spawn<F>(function: F) -> JoinHandle<AsyncSpawnResult<F::Output>>
 where
     F: AsyncRecoverableFunction,
 {
-    spawn(|| {
-        let _: AsyncSpawnResult = async_run_function(function);
+    let handle: Handle = ambient_or_global_handle();
+    spawn(move || async_run_function(function, handle))
+}
+
+/// Schedules `function` as a lightweight tokio task on `handle`, for callers who already manage
+/// their own runtime and don't need a blocking `std::thread::JoinHandle` to join it from sync
+/// code. Unlike `async_recoverable_spawn`, this spends no OS thread and no `block_on` hop --
+/// `function` runs directly on `handle`'s executor and the returned `task::JoinHandle` is
+/// awaited like any other tokio task. Named `..._on_handle` (rather than `recoverable_spawn_on`)
+/// to avoid colliding with `thread::r#async::recoverable_spawn_on_runtime`, which selects a
+/// runtime via the broader `RuntimeKind` enum instead of a bare `Handle`.
+///
+/// - `handle`: The tokio runtime to schedule `function` on.
+/// - `function`: The primary function to execute, implementing the `AsyncRecoverableFunction` trait.
+/// - Returns: A `task::JoinHandle<F::Output>` resolving to the function's return value, or a
+///   `JoinError` if it panicked.
+#[inline]
+pub fn recoverable_spawn_on_handle<F>(handle: Handle, function: F) -> task::JoinHandle<F::Output>
+where
+    F: AsyncRecoverableFunction,
+{
+    handle.spawn(function.call())
+}
+
+/// A cancellable handle to an in-flight async recoverable task.
+///
+/// - Wraps the `std::thread::JoinHandle` used to block on the task's completion together with
+///   an `AbortHandle` for the inner tokio task, so the task can be aborted without first joining it.
+///
+/// `join_handle` is an `Option` rather than a bare `JoinHandle` solely so `join(self)` can `take()`
+/// it out: `Self` implements `Drop`, so a plain field move out of `self` in `join` would be
+/// rejected by the borrow checker (E0509).
+pub struct AsyncRecoverableJoinHandle<T> {
+    join_handle: Option<JoinHandle<AsyncSpawnResult<T>>>,
+    abort_handle: task::AbortHandle,
+    abort_on_drop: bool,
+}
+
+impl<T> AsyncRecoverableJoinHandle<T> {
+    /// Aborts the in-flight task.
+    ///
+    /// - A subsequent `join()` will observe the task as cancelled (an `Err` carrying a cancelled `AsyncSpawnError`).
+    #[inline]
+    pub fn abort(&self) {
+        self.abort_handle.abort();
+    }
+
+    /// - Returns: `true` if the task has already finished running.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.abort_handle.is_finished()
+    }
+
+    /// Configures whether dropping this handle aborts the in-flight task instead of leaving it detached.
+    ///
+    /// - `abort_on_drop`: When `true`, dropping the handle cancels the task.
+    #[inline]
+    pub fn with_abort_on_drop(mut self, abort_on_drop: bool) -> Self {
+        self.abort_on_drop = abort_on_drop;
+        self
+    }
+
+    /// Blocks the current thread until the task completes.
+    ///
+    /// - Returns: A `std::thread::Result` carrying the `AsyncSpawnResult` of the task, or the panic that occurred while joining.
+    #[inline]
+    pub fn join(mut self) -> std::thread::Result<AsyncSpawnResult<T>> {
+        self.join_handle
+            .take()
+            .expect("join_handle is only taken once, by this method")
+            .join()
+    }
+}
+
+impl<T> Drop for AsyncRecoverableJoinHandle<T> {
+    fn drop(&mut self) {
+        if self.abort_on_drop && self.join_handle.is_some() {
+            self.abort_handle.abort();
+        }
+    }
+}
+
+/// Spawns an asynchronous recoverable function and returns an abortable handle to it.
+///
+/// - `function`: A function implementing the `AsyncRecoverableFunction` trait.
+/// - Returns: An `AsyncRecoverableJoinHandle<F::Output>` that can be cancelled with `abort()`,
+///   polled with `is_finished()`, or joined to retrieve the function's return value.
+///
+/// - Never panics due to the runtime lookup itself: the task is scheduled on the caller's
+///   ambient runtime if one is active, falling back to the crate's global runtime otherwise.
+#[inline]
+pub fn async_recoverable_spawn_abortable<F>(function: F) -> AsyncRecoverableJoinHandle<F::Output>
+where
+    F: AsyncRecoverableFunction,
+{
+    let handle: Handle = ambient_or_global_handle();
+    let task: task::JoinHandle<F::Output> = handle.spawn(function.call());
+    let abort_handle: task::AbortHandle = task.abort_handle();
+    let join_handle: JoinHandle<AsyncSpawnResult<F::Output>> =
+        spawn(move || handle.block_on(task).map_err(AsyncSpawnError::from));
+    AsyncRecoverableJoinHandle {
+        join_handle: Some(join_handle),
+        abort_handle,
+        abort_on_drop: false,
+    }
+}
+
+/// Spawns a recoverable function, racing it against a timeout.
+///
+/// - `function`: The primary function to execute, implementing the `AsyncRecoverableFunction` trait.
+/// - `error_handle_function`: A function to handle errors, implementing the `AsyncErrorHandlerFunction` trait.
+///   Invoked with a timeout-specific message if `timeout` elapses before `function` completes.
+/// - `timeout`: The maximum `Duration` to let `function` run before it is aborted.
+/// - Returns: A `JoinHandle` carrying the `AsyncSpawnResult` of `function`, or `Err(AsyncSpawnError::Timeout)`
+///   if the deadline elapsed first.
+#[inline]
+pub fn async_recoverable_spawn_timeout<F, E>(
+    function: F,
+    error_handle_function: E,
+    timeout: Duration,
+) -> JoinHandle<AsyncSpawnResult<F::Output>>
+where
+    F: AsyncRecoverableFunction,
+    E: AsyncErrorHandlerFunction,
+{
+    let handle: Handle = ambient_or_global_handle();
+    spawn(move || {
+        let mut task: task::JoinHandle<F::Output> = handle.spawn(function.call());
+        let run_result: AsyncSpawnResult<F::Output> = handle.block_on(async {
+            select! {
+                res = &mut task => res.map_err(AsyncSpawnError::from),
+                _ = time::sleep(timeout) => {
+                    task.abort();
+                    let _ = (&mut task).await;
+                    Err(AsyncSpawnError::Timeout)
+                }
+            }
+        });
+        if let Err(err) = run_result.as_ref() {
+            let err_string: String = tokio_error_to_string(err);
+            let _: AsyncSpawnResult<()> =
+                async_run_error_handle_function(error_handle_function, err_string, handle.clone());
+        }
+        run_result
     })
 }
 
@@ -84,20 +239,26 @@ where
 ///
 /// - `function`: The primary function to execute, implementing the `AsyncRecoverableFunction` trait.
 /// - `error_handle_function`: A function to handle errors, implementing the `AsyncErrorHandlerFunction` trait.
-/// - Returns: A `JoinHandle<()>` that can be used to manage the spawned thread.
+/// - Returns: A `JoinHandle` carrying the `AsyncSpawnResult` of `function`, which can be used to manage the spawned thread.
 #[inline]
-pub fn async_recoverable_spawn_catch<F, E>(function: F, error_handle_function: E) -> JoinHandle<()>
+pub fn async_recoverable_spawn_catch<F, E>(
+    function: F,
+    error_handle_function: E,
+) -> JoinHandle<AsyncSpawnResult<F::Output>>
 where
     F: AsyncRecoverableFunction,
     E: AsyncErrorHandlerFunction,
 {
-    spawn(|| {
-        let run_result: AsyncSpawnResult = async_run_function(function);
-        if let Err(err) = run_result {
+    let handle: Handle = ambient_or_global_handle();
+    spawn(move || {
+        let run_result: AsyncSpawnResult<F::Output> =
+            async_run_function(function, handle.clone());
+        if let Err(err) = run_result.as_ref() {
             let err_string: String = tokio_error_to_string(err);
-            let _: AsyncSpawnResult =
-                async_run_error_handle_function(error_handle_function, err_string);
+            let _: AsyncSpawnResult<()> =
+                async_run_error_handle_function(error_handle_function, err_string, handle);
         }
+        run_result
     })
 }
 
@@ -115,28 +276,7 @@ where
 /// - `finally`: A function that will be executed after the main function and error handler, which must implement the `AsyncRecoverableFunction` trait.
 ///
 /// # Returns
-/// - A `JoinHandle<()>` that can be used to manage the spawned thread, ensuring that all the functions execute
-///   in a recoverable context and the final block always runs.
-///
-/// # Errors
-/// - If the `function` fails, the `error_handle_function` is invoked. If this fails as well, it will not stop the execution
-///   of the `finally` block.
-/// - The final block (`finally`) is always executed, even if the main function (`function`) or the error handler (`error_handle_function`) fails.
-/// Spawns an asynchronous recoverable function, catches any errors with an error-handling function,
-/// and ensures that a final function is always executed, regardless of whether an error occurred.
-///
-/// This function runs a series of operations in an asynchronous context, where:
-/// - `function` is executed first. If it results in an error, the `error_handle_function` is called.
-/// - After either the main function or the error handler finishes, the `finally` function is executed.
-/// This guarantees that the `finally` function runs regardless of the success or failure of the main operation.
-///
-/// # Parameters
-/// - `function`: The primary function to execute, which must implement the `AsyncRecoverableFunction` trait.
-/// - `error_handle_function`: A function that handles errors, which must implement the `AsyncErrorHandlerFunction` trait.
-/// - `finally`: A function that will be executed after the main function and error handler, which must implement the `AsyncRecoverableFunction` trait.
-///
-/// # Returns
-/// - A `JoinHandle<()>` that can be used to manage the spawned thread, ensuring that all the functions execute
+/// - A `JoinHandle` carrying the `AsyncSpawnResult` of `function`, ensuring that all the functions execute
 ///   in a recoverable context and the final block always runs.
 ///
 /// # Errors
@@ -148,32 +288,87 @@ pub fn async_recoverable_spawn_catch_finally<F, E, L>(
     function: F,
     error_handle_function: E,
     finally: L,
-) -> JoinHandle<()>
+) -> JoinHandle<AsyncSpawnResult<F::Output>>
 where
     F: AsyncRecoverableFunction,
     E: AsyncErrorHandlerFunction,
     L: AsyncRecoverableFunction,
 {
-    spawn(|| {
-        let run_result: AsyncSpawnResult = async_run_function(function);
-        if let Err(err) = run_result {
+    let handle: Handle = ambient_or_global_handle();
+    spawn(move || {
+        let run_result: AsyncSpawnResult<F::Output> =
+            async_run_function(function, handle.clone());
+        if let Err(err) = run_result.as_ref() {
+            let err_string: String = tokio_error_to_string(err);
+            let _: AsyncSpawnResult<()> = async_run_error_handle_function(
+                error_handle_function,
+                err_string,
+                handle.clone(),
+            );
+        }
+        let _: AsyncSpawnResult<L::Output> = async_run_function(finally, handle);
+        run_result
+    })
+}
+
+/// `async_recoverable_spawn_catch_finally` variant that also races `function` against a `timeout`
+/// deadline, guaranteeing `finally` runs whether `function` succeeded, panicked, or was aborted
+/// for running past `timeout` -- `finally` runs unconditionally after the `function`/error-handler
+/// step either way, exactly as in `async_recoverable_spawn_catch_finally`.
+///
+/// - `function`: The primary function to execute, implementing the `AsyncRecoverableFunction` trait.
+/// - `error_handle_function`: A function to handle errors, implementing the `AsyncErrorHandlerFunction` trait.
+///   Invoked with a timeout-specific message if `timeout` elapses before `function` completes.
+/// - `finally`: A function that always runs after `function` (and its error handler, if any),
+///   implementing the `AsyncRecoverableFunction` trait -- including when `function` timed out.
+/// - `timeout`: The maximum `Duration` to let `function` run before it is aborted.
+/// - Returns: A `JoinHandle` carrying the `AsyncSpawnResult` of `function`, or `Err(AsyncSpawnError::Timeout)`
+///   if the deadline elapsed first.
+#[inline]
+pub fn async_recoverable_spawn_catch_finally_timeout<F, E, L>(
+    function: F,
+    error_handle_function: E,
+    finally: L,
+    timeout: Duration,
+) -> JoinHandle<AsyncSpawnResult<F::Output>>
+where
+    F: AsyncRecoverableFunction,
+    E: AsyncErrorHandlerFunction,
+    L: AsyncRecoverableFunction,
+{
+    let handle: Handle = ambient_or_global_handle();
+    spawn(move || {
+        let mut task: task::JoinHandle<F::Output> = handle.spawn(function.call());
+        let run_result: AsyncSpawnResult<F::Output> = handle.block_on(async {
+            select! {
+                res = &mut task => res.map_err(AsyncSpawnError::from),
+                _ = time::sleep(timeout) => {
+                    task.abort();
+                    let _ = (&mut task).await;
+                    Err(AsyncSpawnError::Timeout)
+                }
+            }
+        });
+        if let Err(err) = run_result.as_ref() {
             let err_string: String = tokio_error_to_string(err);
-            let _: AsyncSpawnResult =
-                async_run_error_handle_function(error_handle_function, err_string);
+            let _: AsyncSpawnResult<()> = async_run_error_handle_function(
+                error_handle_function,
+                err_string,
+                handle.clone(),
+            );
         }
-        let _: AsyncSpawnResult = async_run_function(finally);
+        let _: AsyncSpawnResult<L::Output> = async_run_function(finally, handle);
+        run_result
     })
 }
 
 /// Executes a recoverable function within a panic-safe context.
 ///
 /// - `func`: A function implementing the `RecoverableFunction` trait.
-/// - Returns: A `SpawnResult` indicating the success or failure of the function execution.
+/// - Returns: A `SpawnResult` carrying the function's return value, or the panic that occurred.
 #[inline]
-pub fn run_function<F: RecoverableFunction>(func: F) -> SpawnResult {
-    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        func();
-    }))
+pub fn run_function<F: RecoverableFunction>(func: F) -> SpawnResult<F::Output> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| func.call()))
 }
 
 /// Executes an error-handling function with a given error message within a panic-safe context.
@@ -182,7 +377,7 @@ pub fn run_function<F: RecoverableFunction>(func: F) -> SpawnResult {
 /// - `error`: A string slice representing the error message.
 /// - Returns: A `SpawnResult` indicating the success or failure of the error-handling function execution.
 #[inline]
-pub fn run_error_handle_function<E: ErrorHandlerFunction>(func: E, error: &str) -> SpawnResult {
+pub fn run_error_handle_function<E: ErrorHandlerFunction>(func: E, error: &str) -> SpawnResult<()> {
     std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
         func(error);
     }))
@@ -193,7 +388,7 @@ pub fn run_error_handle_function<E: ErrorHandlerFunction>(func: E, error: &str)
 /// - `err`: The captured error value, of type `BoxAnySend`.
 /// - Returns: A string representation of the error value.
 #[inline]
-pub fn spawn_error_to_string(err: BoxAnySend) -> String {
+pub fn spawn_error_to_string(err: &BoxAnySend) -> String {
     match err.downcast_ref::<&str>() {
         Some(str_slice) => str_slice.to_string(),
         None => match err.downcast_ref::<String>() {
@@ -203,6 +398,21 @@ pub fn spawn_error_to_string(err: BoxAnySend) -> String {
     }
 }
 
+/// Executes an error-handling function with the raw, un-stringified panic payload within a panic-safe context.
+///
+/// - `func`: A function implementing the `PanicErrorHandlerFunction` trait.
+/// - `payload`: The captured panic payload, of type `BoxAnySend`.
+/// - Returns: A `SpawnResult` indicating the success or failure of the error-handling function execution.
+#[inline]
+pub fn run_panic_error_handle_function<E: PanicErrorHandlerFunction>(
+    func: E,
+    payload: &BoxAnySend,
+) -> SpawnResult<()> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        func(payload);
+    }))
+}
+
 /// Spawns a new thread to run the provided function `function` in a recoverable manner.
 /// If the function `function` panics during execution, the panic will be caught, and the thread
 /// will terminate without crashing the entire program.
@@ -215,39 +425,100 @@ pub fn spawn_error_to_string(err: BoxAnySend) -> String {
 ///     - `'static`: The function does not contain references to non-static data (i.e., data that lives beyond the function's scope).
 ///
 /// # Returns
-/// - A `JoinHandle<()>` representing the spawned thread. The thread can be joined later to wait for its completion.
+/// - A `JoinHandle<SpawnResult<F::Output>>` representing the spawned thread. The thread can be joined
+///   later to retrieve the function's return value, or the panic that occurred.
 ///
 ///
 /// # Panics
 /// - This function itself will not panic, but the function `function` could panic during execution.
 ///   The panic will be caught, preventing the program from crashing.
 #[inline]
-pub fn recoverable_spawn<F>(function: F) -> JoinHandle<()>
+pub fn recoverable_spawn<F>(function: F) -> JoinHandle<SpawnResult<F::Output>>
 where
     F: RecoverableFunction,
 {
-    spawn(|| {
-        let _: SpawnResult = run_function(function);
-    })
+    spawn(|| run_function(function))
 }
 
 /// Spawns a recoverable function with an error-handling function in a new thread.
 ///
 /// - `function`: The primary function to execute, implementing the `RecoverableFunction` trait.
 /// - `error_handle_function`: A function to handle errors, implementing the `ErrorHandlerFunction` trait.
-/// - Returns: A `JoinHandle<()>` that can be used to manage the spawned thread.
+/// - Returns: A `JoinHandle` carrying the `SpawnResult` of `function`, which can be used to manage the spawned thread.
 #[inline]
-pub fn recoverable_spawn_catch<F, E>(function: F, error_handle_function: E) -> JoinHandle<()>
+pub fn recoverable_spawn_catch<F, E>(
+    function: F,
+    error_handle_function: E,
+) -> JoinHandle<SpawnResult<F::Output>>
 where
     F: RecoverableFunction,
     E: ErrorHandlerFunction,
 {
     spawn(|| {
-        let run_result: SpawnResult = run_function(function);
-        if let Err(err) = run_result {
+        let run_result: SpawnResult<F::Output> = run_function(function);
+        if let Err(err) = run_result.as_ref() {
             let err_string: String = spawn_error_to_string(err);
-            let _: SpawnResult = run_error_handle_function(error_handle_function, &err_string);
+            let _: SpawnResult<()> = run_error_handle_function(error_handle_function, &err_string);
+        }
+        run_result
+    })
+}
+
+/// Spawns a recoverable function with a raw-panic-payload error-handling function in a new thread.
+///
+/// - `function`: The primary function to execute, implementing the `RecoverableFunction` trait.
+/// - `error_handle_function`: A function to handle the raw panic payload, implementing the `PanicErrorHandlerFunction` trait.
+/// - Returns: A `JoinHandle` carrying the `SpawnResult` of `function`, which can be used to manage the spawned thread.
+#[inline]
+pub fn recoverable_spawn_catch_panic<F, E>(
+    function: F,
+    error_handle_function: E,
+) -> JoinHandle<SpawnResult<F::Output>>
+where
+    F: RecoverableFunction,
+    E: PanicErrorHandlerFunction,
+{
+    spawn(|| {
+        let run_result: SpawnResult<F::Output> = run_function(function);
+        if let Err(err) = run_result.as_ref() {
+            let _: SpawnResult<()> = run_panic_error_handle_function(error_handle_function, err);
+        }
+        run_result
+    })
+}
+
+/// Executes a recoverable function within a panic-safe context, capturing structured `PanicInfo`
+/// (payload, thread name, source location, backtrace) rather than discarding everything but the
+/// return value.
+///
+/// - `func`: A function implementing the `RecoverableFunction` trait.
+/// - Returns: `Ok` with the function's return value, or `Err` with the captured `PanicInfo`.
+#[inline]
+pub fn run_function_info<F: RecoverableFunction>(func: F) -> Result<F::Output, PanicInfo> {
+    capture_panic_info(|| func.call())
+}
+
+/// Spawns a recoverable function with an error-handling function that receives the full
+/// `PanicInfo` (payload, thread name, source location, backtrace) in a new thread.
+///
+/// - `function`: The primary function to execute, implementing the `RecoverableFunction` trait.
+/// - `error_handle_function`: A function to handle the panic, implementing the `PanicInfoHandlerFunction` trait.
+/// - Returns: A `JoinHandle` carrying the result of `function`, which can be used to manage the spawned thread.
+#[inline]
+pub fn recoverable_spawn_catch_info<F, E>(
+    function: F,
+    error_handle_function: E,
+) -> JoinHandle<Result<F::Output, PanicInfo>>
+where
+    F: RecoverableFunction,
+    E: PanicInfoHandlerFunction,
+{
+    spawn(|| {
+        let run_result: Result<F::Output, PanicInfo> = run_function_info(function);
+        if let Err(info) = run_result.as_ref() {
+            error_handle_function(info);
         }
+        run_result
     })
 }
 
@@ -256,18 +527,88 @@ pub fn recoverable_spawn_catch_finally<F, E, L>(
     function: F,
     error_handle_function: E,
     finally: L,
-) -> JoinHandle<()>
+) -> JoinHandle<SpawnResult<F::Output>>
 where
     F: RecoverableFunction,
     E: ErrorHandlerFunction,
     L: RecoverableFunction,
 {
     spawn(|| {
-        let run_result: SpawnResult = run_function(function);
-        if let Err(err) = run_result {
+        let run_result: SpawnResult<F::Output> = run_function(function);
+        if let Err(err) = run_result.as_ref() {
             let err_string: String = spawn_error_to_string(err);
-            let _: SpawnResult = run_error_handle_function(error_handle_function, &err_string);
+            let _: SpawnResult<()> = run_error_handle_function(error_handle_function, &err_string);
         }
-        let _: SpawnResult = run_function(finally);
+        let _: SpawnResult<L::Output> = run_function(finally);
+        run_result
     })
 }
+
+/// Spawns `function` on a new thread, racing it against a `timeout` deadline.
+///
+/// Unlike the async timeout variants (`async_recoverable_spawn_timeout`,
+/// `recoverable_spawn_timeout_on`), a plain OS thread has no cancellation primitive: if `timeout`
+/// elapses first, the spawned thread is NOT killed -- it keeps running in the background and its
+/// eventual result (or panic) is discarded once the deadline has already been reported.
+///
+/// - `function`: The primary function to execute, implementing the `RecoverableFunction` trait.
+/// - `error_handle_function`: A function to handle errors, implementing the `ErrorHandlerFunction` trait.
+///   Invoked with a timeout-specific message if `timeout` elapses before `function` completes.
+/// - `timeout`: The maximum `Duration` to wait for `function` before giving up on it.
+/// - Returns: A `SpawnTimeoutResult` carrying the function's return value, the panic that
+///   occurred, or `Err(SpawnTimeoutError::Timeout)` if the deadline elapsed first.
+#[inline]
+pub fn recoverable_spawn_timeout<F, E>(
+    function: F,
+    error_handle_function: E,
+    timeout: Duration,
+) -> SpawnTimeoutResult<F::Output>
+where
+    F: RecoverableFunction,
+    E: ErrorHandlerFunction,
+{
+    let (sender, receiver) = std::sync::mpsc::channel();
+    spawn(move || {
+        let _ = sender.send(run_function(function));
+    });
+    let run_result: SpawnTimeoutResult<F::Output> = match receiver.recv_timeout(timeout) {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(payload)) => Err(SpawnTimeoutError::Panic(payload)),
+        Err(_) => Err(SpawnTimeoutError::Timeout),
+    };
+    if let Err(err) = run_result.as_ref() {
+        let err_string: String = err.to_string();
+        let _: SpawnResult<()> = run_error_handle_function(error_handle_function, &err_string);
+    }
+    run_result
+}
+
+/// Spawns a scoped thread to run the provided function `function` in a recoverable manner,
+/// allowing the function to borrow data from the enclosing scope instead of requiring `'static`.
+///
+/// # Parameters
+/// - `scope`: The `std::thread::Scope` the thread is spawned into. The scope guarantees the
+///   thread is joined before any data it borrows goes out of scope.
+/// - `function`: A function of type `function` to be executed in the scoped thread. It must implement `FnOnce() -> T`, `Send`, and `'scope` traits.
+///     - `FnOnce() -> T`: The function is callable with no arguments and produces a value of type `T`.
+///     - `Send`: The function can be safely transferred across thread boundaries.
+///     - `'scope`: The function may borrow data that outlives the scope, but need not be `'static`.
+///
+/// # Returns
+/// - A `ScopedJoinHandle<'scope, SpawnResult<T>>` representing the spawned thread. The thread can be
+///   joined later to retrieve the function's return value, or the panic that occurred.
+///
+/// # Panics
+/// - This function itself will not panic, but the function `function` could panic during execution.
+///   The panic will be caught, preventing the program from crashing or the scope from failing to join.
+#[inline]
+pub fn recoverable_spawn_scoped<'scope, 'env, F, T>(
+    scope: &'scope std::thread::Scope<'scope, 'env>,
+    function: F,
+) -> std::thread::ScopedJoinHandle<'scope, SpawnResult<T>>
+where
+    F: FnOnce() -> T + Send + 'scope,
+    T: Send + 'scope,
+{
+    scope.spawn(move || std::panic::catch_unwind(std::panic::AssertUnwindSafe(function)))
+}
@@ -1,3 +1,4 @@
+use super::r#type::{BoxAnySend, BoxFuture, PanicInfo};
 use std::{future::Future, sync::Arc};
 
 /// Trait alias for functions that can be executed in a recoverable context.
@@ -52,6 +53,40 @@ where
     }
 }
 
+/// Trait alias for asynchronous functions that can be executed in a recoverable context without
+/// requiring the future to be `Send`, for tasks that touch `!Send` state such as `Rc`/`RefCell`
+/// (e.g. driving a `LocalSet`-based executor). `AsyncRecoverableFunction` cannot express this
+/// because its `Future` associated type is bounded by `Send`.
+///
+/// # Arguments
+///
+/// - `FnOnce() -> Future` - Function that returns a `!Send` Future.
+///
+/// # Returns
+///
+/// - `Future` - The asynchronous computation result.
+pub trait LocalAsyncRecoverableFunction: 'static {
+    type Output: 'static;
+    type Future: Future<Output = Self::Output> + 'static;
+
+    /// Executes the asynchronous function.
+    fn call(self) -> Self::Future;
+}
+
+impl<F, Fut, O> LocalAsyncRecoverableFunction for F
+where
+    F: FnOnce() -> Fut + 'static,
+    Fut: Future<Output = O> + 'static,
+    O: 'static,
+{
+    type Output = O;
+    type Future = Fut;
+
+    fn call(self) -> Self::Future {
+        self()
+    }
+}
+
 /// Trait alias for asynchronous error-handling functions used in a recoverable context.
 ///
 /// # Arguments
@@ -82,12 +117,33 @@ where
     }
 }
 
-/// Trait alias for functions that can be executed in a recoverable context.
+/// Trait for functions that can be executed in a recoverable context.
 ///
-/// - Functions implementing this trait must satisfy `FnOnce() + Send + Sync + 'static`.
-pub trait RecoverableFunction: FnOnce() + Send + Sync + 'static {}
+/// # Arguments
+///
+/// - `FnOnce() -> O` - Function that produces a value.
+///
+/// # Returns
+///
+/// - `O` - The computation result.
+pub trait RecoverableFunction: Send + Sync + 'static {
+    type Output: Send;
 
-impl<T> RecoverableFunction for T where T: FnOnce() + Send + Sync + 'static {}
+    /// Executes the function.
+    fn call(self) -> Self::Output;
+}
+
+impl<F, O> RecoverableFunction for F
+where
+    F: FnOnce() -> O + Send + Sync + 'static,
+    O: Send + 'static,
+{
+    type Output = O;
+
+    fn call(self) -> Self::Output {
+        self()
+    }
+}
 
 /// Trait alias for error-handling functions used in a recoverable context.
 ///
@@ -97,3 +153,80 @@ impl<T> RecoverableFunction for T where T: FnOnce() + Send + Sync + 'static {}
 pub trait ErrorHandlerFunction: FnOnce(&str) + Send + Sync + 'static {}
 
 impl<T> ErrorHandlerFunction for T where T: FnOnce(&str) + Send + Sync + 'static {}
+
+/// Trait alias for error-handling functions that receive the raw, un-stringified panic payload.
+///
+/// # Arguments
+///
+/// - `&BoxAnySend` - The captured panic payload to handle.
+pub trait PanicErrorHandlerFunction: FnOnce(&BoxAnySend) + Send + Sync + 'static {}
+
+impl<T> PanicErrorHandlerFunction for T where T: FnOnce(&BoxAnySend) + Send + Sync + 'static {}
+
+/// Trait alias for error-handling functions that receive the full `PanicInfo` (payload, thread
+/// name, source location, backtrace) instead of an eagerly-stringified message. Existing
+/// `ErrorHandlerFunction`/`PanicErrorHandlerFunction` handlers are unaffected and keep working
+/// unchanged via `recoverable_spawn_catch`/`recoverable_spawn_catch_panic`; this is an additive
+/// trait for callers that want the full context. Used by the synchronous structured catch
+/// handlers (`recoverable_spawn_catch_info`, `recoverable_spawn_catch_structured`), whether or
+/// not site info beyond the payload is actually populated; the async entry points take
+/// `AsyncPanicInfoHandlerFunction` instead.
+///
+/// # Arguments
+///
+/// - `&PanicInfo` - The captured panic context to handle.
+pub trait PanicInfoHandlerFunction: FnOnce(&PanicInfo) + Send + Sync + 'static {}
+
+impl<T> PanicInfoHandlerFunction for T where T: FnOnce(&PanicInfo) + Send + Sync + 'static {}
+
+/// Asynchronous counterpart of `PanicInfoHandlerFunction`.
+///
+/// # Arguments
+///
+/// - `Arc<PanicInfo>` - The captured panic context to handle.
+///
+/// # Returns
+///
+/// - `Future` - The asynchronous error handling result.
+pub trait AsyncPanicInfoHandlerFunction: Send + Sync + 'static {
+    type Future: Future<Output = ()> + Send;
+
+    /// Handles the captured panic context asynchronously.
+    ///
+    /// - `info`: The captured panic context to handle.
+    fn call(self, info: Arc<PanicInfo>) -> Self::Future;
+}
+
+impl<F, Fut> AsyncPanicInfoHandlerFunction for F
+where
+    F: FnOnce(Arc<PanicInfo>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    type Future = Fut;
+
+    fn call(self, info: Arc<PanicInfo>) -> Self::Future {
+        self(info)
+    }
+}
+
+/// A reusable, shareable async error handler, invoked through `Arc<Self>` rather than consumed
+/// after a single call like `AsyncErrorHandlerFunction`.
+///
+/// Register one handler instance and reuse it across many spawns, retries, and finally-blocks
+/// without re-allocating a closure for each failure.
+pub trait ErrorHandler: Send + Sync + 'static {
+    /// Handles an error asynchronously.
+    ///
+    /// - `error`: The error message to handle.
+    fn handle(self: Arc<Self>, error: Arc<String>) -> BoxFuture<'static, ()>;
+}
+
+impl<F, Fut> ErrorHandler for F
+where
+    F: Fn(Arc<String>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    fn handle(self: Arc<Self>, error: Arc<String>) -> BoxFuture<'static, ()> {
+        Box::pin((self)(error))
+    }
+}
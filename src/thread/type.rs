@@ -1,4 +1,6 @@
 use crate::*;
+use std::cell::RefCell;
+use std::panic::Location;
 use std::{any::Any, sync::Arc};
 use tokio::task::JoinError;
 
@@ -10,15 +12,150 @@ pub type BoxAnySend = Box<dyn Any + Send>;
 
 /// Type alias for the result type returned by spawnable functions.
 ///
-/// - `Ok(())`: Indicates successful execution of the function.
-/// - `Err(JoinError)`: Contains a error value in case of a panic or failure.
-pub type AsyncSpawnResult = Result<(), JoinError>;
+/// - `Ok(value)`: Contains the value produced by the spawned function.
+/// - `Err(AsyncSpawnError)`: Contains a crate-owned error value in case of a panic or cancellation.
+///
+/// Defaults `T` to `()` for the call sites in `thread::r#async` that discard the task's return
+/// value; call sites that need the typed value (e.g. `thread::spawn`) spell out `AsyncSpawnResult<F::Output>`.
+pub type AsyncSpawnResult<T = ()> = Result<T, AsyncSpawnError>;
+
+/// Crate-owned error produced when a spawned asynchronous recoverable task fails to run to
+/// completion. Replaces `tokio::task::JoinError` in `AsyncSpawnResult` so callers depend on a
+/// type this crate owns rather than one named after -- and coupled to -- a specific executor;
+/// `From<JoinError>` is how the tokio-backed call sites in `thread::r#async`/`thread::spawn`
+/// produce one.
+#[derive(Debug)]
+pub enum AsyncSpawnError {
+    /// The task panicked; carries the raw panic payload, as returned by `JoinError::into_panic`.
+    Panic(BoxAnySend),
+    /// The task was cancelled (aborted directly, or the runtime it was spawned on shut down)
+    /// before completing, for a reason other than a timeout.
+    Cancelled,
+    /// The task was aborted because a deadline (`..._timeout`/`..._timeout_on`) elapsed before it
+    /// completed. Kept distinct from `Cancelled` so callers can tell "ran out of time" apart from
+    /// any other reason a task didn't finish -- both are produced by aborting the same underlying
+    /// `JoinError`-returning task, which otherwise can't tell the two apart on its own.
+    Timeout,
+}
+
+impl AsyncSpawnError {
+    /// - Returns: `true` if the task panicked rather than being cancelled or timing out.
+    pub fn is_panic(&self) -> bool {
+        matches!(self, Self::Panic(_))
+    }
+
+    /// - Returns: `true` if the task was cancelled for a reason other than a timeout.
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, Self::Cancelled)
+    }
+
+    /// - Returns: `true` if the task was aborted because its deadline elapsed.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Self::Timeout)
+    }
+
+    /// Consumes the error, returning the raw panic payload.
+    ///
+    /// # Panics
+    /// - Panics if this error represents a cancellation or timeout rather than a panic.
+    pub fn into_panic(self) -> BoxAnySend {
+        match self {
+            Self::Panic(payload) => payload,
+            Self::Cancelled => panic!("called `AsyncSpawnError::into_panic` on a cancelled task"),
+            Self::Timeout => panic!("called `AsyncSpawnError::into_panic` on a timed-out task"),
+        }
+    }
+}
+
+impl std::fmt::Display for AsyncSpawnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Panic(payload) => write!(f, "task panicked: {}", payload_message(payload)),
+            Self::Cancelled => write!(f, "task was cancelled"),
+            Self::Timeout => write!(f, "task timed out"),
+        }
+    }
+}
+
+impl std::error::Error for AsyncSpawnError {}
+
+impl From<JoinError> for AsyncSpawnError {
+    fn from(err: JoinError) -> Self {
+        if err.is_panic() {
+            Self::Panic(err.into_panic())
+        } else {
+            Self::Cancelled
+        }
+    }
+}
+
+fn payload_message(payload: &BoxAnySend) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(str_slice) => str_slice.to_string(),
+        None => match payload.downcast_ref::<String>() {
+            Some(string) => string.to_owned(),
+            None => format!("{:?}", payload),
+        },
+    }
+}
 
 /// Type alias for the result type returned by spawnable functions.
 ///
-/// - `Ok(())`: Indicates successful execution of the function.
+/// - `Ok(value)`: Contains the value produced by the spawned function.
 /// - `Err(BoxAnySend)`: Contains a boxed error value in case of a panic or failure.
-pub type SpawnResult = Result<(), BoxAnySend>;
+pub type SpawnResult<T> = Result<T, BoxAnySend>;
+
+/// Error produced by `recoverable_spawn_timeout`. Unlike `AsyncSpawnError`, there is no
+/// `Cancelled` variant: a plain OS thread has no cancellation primitive, so the only two ways
+/// the spawned function can fail to produce a value are panicking or the deadline elapsing first.
+#[derive(Debug)]
+pub enum SpawnTimeoutError {
+    /// The function panicked; carries the raw panic payload, exactly like `SpawnResult`'s `Err`.
+    Panic(BoxAnySend),
+    /// The deadline elapsed before the function finished. The spawned thread is NOT killed -- it
+    /// keeps running in the background and its eventual result (or panic) is discarded.
+    Timeout,
+}
+
+impl SpawnTimeoutError {
+    /// - Returns: `true` if the function panicked rather than timing out.
+    pub fn is_panic(&self) -> bool {
+        matches!(self, Self::Panic(_))
+    }
+
+    /// - Returns: `true` if the deadline elapsed before the function finished.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Self::Timeout)
+    }
+
+    /// Consumes the error, returning the raw panic payload.
+    ///
+    /// # Panics
+    /// - Panics if this error represents a timeout rather than a panic.
+    pub fn into_panic(self) -> BoxAnySend {
+        match self {
+            Self::Panic(payload) => payload,
+            Self::Timeout => panic!("called `SpawnTimeoutError::into_panic` on a timed-out task"),
+        }
+    }
+}
+
+impl std::fmt::Display for SpawnTimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Panic(payload) => write!(f, "task panicked: {}", payload_message(payload)),
+            Self::Timeout => write!(f, "task timed out"),
+        }
+    }
+}
+
+impl std::error::Error for SpawnTimeoutError {}
+
+/// Type alias for the result type returned by `recoverable_spawn_timeout`.
+///
+/// - `Ok(value)`: Contains the value produced by the spawned function.
+/// - `Err(SpawnTimeoutError)`: Contains the panic payload, or records that the deadline elapsed.
+pub type SpawnTimeoutResult<T> = Result<T, SpawnTimeoutError>;
 
 /// Alias for an `Arc`-wrapped recoverable function.
 ///
@@ -32,3 +169,213 @@ pub type ArcAsyncRecoverableFunction<O, F> =
 /// - This type represents an `Arc`-wrapped version of any function implementing the `AsyncErrorHandlerFunction` trait.
 /// - Allows shared ownership and thread-safe handling of errors with custom logic across multiple threads.
 pub type ArcAsyncErrorHandlerFunction<O> = Arc<dyn AsyncErrorHandlerFunction<Future = O>>;
+
+/// Alias for a boxed, pinned future, used by traits that must name a future's type without
+/// an associated type (e.g. trait objects built on `Arc<dyn Trait>`).
+pub type BoxFuture<'a, T> = std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send + 'a>>;
+
+/// The downcast panic payload captured by `PanicInfo`.
+#[derive(Debug)]
+pub enum PanicPayload {
+    /// The payload downcast cleanly to `&str` or `String`.
+    Message(String),
+    /// The payload was some other type; kept boxed so handlers can downcast it themselves.
+    Raw(BoxAnySend),
+    /// The task was cancelled (e.g. aborted) before it completed, rather than panicking.
+    Cancelled,
+}
+
+/// Structured error produced by a failed recoverable task: the downcast panic payload (or the
+/// fact that the task was cancelled instead of panicking), the panicking thread's name, the
+/// source location, and a backtrace if available. Handlers implementing
+/// `PanicInfoHandlerFunction` or `AsyncPanicInfoHandlerFunction` receive a `PanicInfo`.
+#[derive(Debug)]
+pub struct PanicInfo {
+    pub payload: PanicPayload,
+    pub thread_name: Option<String>,
+    pub file: Option<String>,
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+    pub backtrace: Option<std::backtrace::Backtrace>,
+}
+
+impl PanicInfo {
+    /// - Returns: `true` if this represents a panic rather than a cancellation.
+    pub fn is_panic(&self) -> bool {
+        !matches!(self.payload, PanicPayload::Cancelled)
+    }
+
+    /// - Returns: `true` if this represents a cancellation rather than a panic.
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self.payload, PanicPayload::Cancelled)
+    }
+
+    /// Consumes the info, returning the panic payload. A payload stored as
+    /// `PanicPayload::Message` (originally `&str` or `String`) comes back as a boxed `String`.
+    ///
+    /// # Panics
+    /// - Panics if this info represents a cancellation rather than a panic.
+    pub fn into_panic(self) -> BoxAnySend {
+        match self.payload {
+            PanicPayload::Raw(payload) => payload,
+            PanicPayload::Message(message) => Box::new(message),
+            PanicPayload::Cancelled => {
+                panic!("called `PanicInfo::into_panic` on a cancelled task")
+            }
+        }
+    }
+
+    /// Attempts to downcast the panic payload to a concrete type `T`.
+    ///
+    /// - Returns: `Some(&T)` if this is a panic carrying a payload of type `T`, otherwise `None`.
+    ///   A payload stored as `PanicPayload::Message` (originally `&str` or `String`) downcasts
+    ///   only as `String`.
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        match &self.payload {
+            PanicPayload::Raw(payload) => payload.downcast_ref::<T>(),
+            PanicPayload::Message(message) => (message as &dyn Any).downcast_ref::<T>(),
+            PanicPayload::Cancelled => None,
+        }
+    }
+
+    /// Formats the payload as a human-readable message.
+    pub fn message(&self) -> String {
+        match &self.payload {
+            PanicPayload::Message(message) => message.clone(),
+            PanicPayload::Raw(payload) => format!("{:?}", payload),
+            PanicPayload::Cancelled => "task was cancelled".to_string(),
+        }
+    }
+}
+
+impl From<JoinError> for PanicInfo {
+    fn from(err: JoinError) -> Self {
+        panic_info_from_join_error(err)
+    }
+}
+
+impl From<AsyncSpawnError> for PanicInfo {
+    fn from(err: AsyncSpawnError) -> Self {
+        panic_info_from_async_spawn_error(err)
+    }
+}
+
+struct PanicSite {
+    thread_name: Option<String>,
+    file: Option<String>,
+    line: Option<u32>,
+    column: Option<u32>,
+    backtrace: Option<std::backtrace::Backtrace>,
+}
+
+thread_local! {
+    /// Keyed per-thread rather than through a shared global, so two tasks catching panics
+    /// concurrently on different threads can never clobber or read back each other's site info.
+    static CAPTURED_PANIC_SITE: RefCell<Option<PanicSite>> = const { RefCell::new(None) };
+}
+
+fn capturing_hook(info: &std::panic::PanicHookInfo<'_>) {
+    let location: Option<&Location<'_>> = info.location();
+    let site = PanicSite {
+        thread_name: std::thread::current().name().map(str::to_string),
+        file: location.map(|location| location.file().to_string()),
+        line: location.map(Location::line),
+        column: location.map(Location::column),
+        backtrace: Some(std::backtrace::Backtrace::capture()),
+    };
+    CAPTURED_PANIC_SITE.with(|slot| *slot.borrow_mut() = Some(site));
+}
+
+/// Combines a `catch_unwind`-style panic payload with whatever site info the most recent
+/// `capturing_hook` invocation on *this thread* recorded into a `PanicInfo`.
+fn panic_info_from_payload(payload: BoxAnySend) -> PanicInfo {
+    let site: Option<PanicSite> = CAPTURED_PANIC_SITE.with(|slot| slot.borrow_mut().take());
+    let message: Option<String> = match payload.downcast_ref::<&str>() {
+        Some(str_slice) => Some(str_slice.to_string()),
+        None => payload.downcast_ref::<String>().map(|string| string.to_owned()),
+    };
+    let payload: PanicPayload = match message {
+        Some(message) => PanicPayload::Message(message),
+        None => PanicPayload::Raw(payload),
+    };
+    PanicInfo {
+        payload,
+        thread_name: site.as_ref().and_then(|site| site.thread_name.clone()),
+        file: site.as_ref().and_then(|site| site.file.clone()),
+        line: site.as_ref().and_then(|site| site.line),
+        column: site.as_ref().and_then(|site| site.column),
+        backtrace: site.and_then(|site| site.backtrace),
+    }
+}
+
+/// Runs `f` inside a scope that temporarily installs a panic hook capturing the panicking
+/// thread's name, source location, and backtrace, restoring the prior hook (whatever it was)
+/// before returning. On panic, combines the hook-captured site with the owned payload from
+/// `catch_unwind` into a `PanicInfo`.
+///
+/// Reading the captured site back is safe from concurrently-panicking threads: it's kept in
+/// thread-local storage, read back on this same thread immediately after `catch_unwind` returns.
+/// Installing and restoring the hook itself is NOT safe to race: `take_hook`/`set_hook` act on a
+/// single process-global hook, so if two threads call `capture_panic_info` (or
+/// `install_panic_site_hook`) concurrently, one can restore its hook out from under the other
+/// mid-call, or capture the other's `capturing_hook` as its own "prior" hook -- in the worst case
+/// leaving `capturing_hook` installed as the program's panic hook even after both calls return.
+/// Callers that need a hard guarantee should serialize their own calls to these functions.
+pub fn capture_panic_info<T>(f: impl FnOnce() -> T) -> Result<T, PanicInfo> {
+    let prior_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(capturing_hook));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+    std::panic::set_hook(prior_hook);
+    result.map_err(panic_info_from_payload)
+}
+
+/// Installs the panic-site-capturing hook, returning the prior hook so it can be restored
+/// afterward. Used around control flow that can't be expressed as a single closure passed to
+/// `capture_panic_info` (e.g. spawning a task onto another executor and awaiting its handle).
+///
+/// Because the captured site lives in thread-local storage, this only recovers site info if the
+/// panic actually happens on the same thread that installed the hook and later reads the slot
+/// back. For a task spawned onto a multi-threaded executor that may run on a different worker
+/// thread, `file`/`line`/`column`/`backtrace` can legitimately come back `None` -- which is the
+/// safe outcome (a miss) rather than silently returning another thread's unrelated site info.
+///
+/// Shares `capture_panic_info`'s process-global hook race: `take_hook`/`set_hook` are not scoped
+/// per-call, so an `install_panic_site_hook`/`restore_panic_site_hook` pair overlapping with
+/// another thread's call to either function (or to `capture_panic_info`) can restore the wrong
+/// hook or leave `capturing_hook` permanently installed. Callers needing a hard guarantee must
+/// serialize their own calls to these functions.
+pub fn install_panic_site_hook() -> Box<dyn Fn(&std::panic::PanicHookInfo<'_>) + Sync + Send> {
+    let prior_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(capturing_hook));
+    prior_hook
+}
+
+/// Restores the panic hook returned by `install_panic_site_hook`.
+pub fn restore_panic_site_hook(prior_hook: Box<dyn Fn(&std::panic::PanicHookInfo<'_>) + Sync + Send>) {
+    std::panic::set_hook(prior_hook);
+}
+
+/// Builds a `PanicInfo` from a `JoinError`, combining whatever site info was captured around the
+/// join with the task's panic payload, or a cancellation payload if the task was aborted rather
+/// than panicking.
+pub fn panic_info_from_join_error(err: JoinError) -> PanicInfo {
+    AsyncSpawnError::from(err).into()
+}
+
+/// Builds a `PanicInfo` from an `AsyncSpawnError`, combining whatever site info was captured
+/// around the join with the task's panic payload, or a cancellation payload if the task was
+/// cancelled rather than panicking.
+pub fn panic_info_from_async_spawn_error(err: AsyncSpawnError) -> PanicInfo {
+    if err.is_panic() {
+        panic_info_from_payload(err.into_panic())
+    } else {
+        PanicInfo {
+            payload: PanicPayload::Cancelled,
+            thread_name: None,
+            file: None,
+            line: None,
+            column: None,
+            backtrace: None,
+        }
+    }
+}